@@ -1,8 +1,10 @@
 use clap::Parser;
 use owo_colors::OwoColorize;
-use serde::Deserialize;
+use serde::de::{MapAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
 use serde_json::{Error, Map, Value};
-use std::{collections::HashMap, io, process::exit};
+use std::io::IsTerminal;
+use std::{collections::HashMap, fmt, io, process::exit};
 
 /// Pretty print a stream of json logs.
 #[derive(Parser, Debug)]
@@ -10,6 +12,183 @@ struct Opt {
     /// Color scheme to use [chalk, greyscale, ocean, solarized, mocha]
     #[clap(long, default_value = "ocean")]
     color_scheme: String,
+
+    /// When to colorize output
+    #[clap(long, value_enum, default_value_t = ColorMode::Auto)]
+    color: ColorMode,
+
+    /// Color depth to render at; auto-detected from $COLORTERM and $TERM when unset
+    #[clap(long, value_enum)]
+    color_depth: Option<ColorDepth>,
+
+    /// Interpret ANSI and caret (^1) color codes embedded in string values
+    #[clap(long)]
+    render_embedded_color: bool,
+
+    /// Tint the whole line by severity when an object has a recognized level field
+    #[clap(long)]
+    highlight_level: bool,
+}
+
+/// When to emit color escape sequences.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum ColorMode {
+    /// Colorize only when stdout is a terminal.
+    Auto,
+    /// Always colorize, even when piped.
+    Always,
+    /// Never colorize.
+    Never,
+}
+
+impl ColorMode {
+    /// Resolve the mode against the current stdout to a concrete formatter,
+    /// rendering at `depth` when color is enabled.
+    fn formatter(self, depth: ColorDepth) -> Box<dyn ColorSink> {
+        let colorize = match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => io::stdout().is_terminal(),
+        };
+        if colorize {
+            Box::new(ColorFormatter { depth })
+        } else {
+            Box::new(PlainFormatter)
+        }
+    }
+}
+
+/// The color capability of the target terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ColorDepth {
+    /// 24-bit truecolor.
+    #[value(name = "truecolor")]
+    TrueColor,
+    /// The xterm 256-color palette.
+    #[value(name = "256")]
+    Ansi256,
+    /// The 16 standard ANSI colors.
+    #[value(name = "16")]
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Detect the terminal's color depth from the environment: a `$COLORTERM`
+    /// containing `truecolor` or `24bit` signals truecolor, a `*-256color`
+    /// `$TERM` signals 256 colors, and
+    /// everything else falls back to the 16 standard colors.
+    fn detect() -> ColorDepth {
+        if let Ok(colorterm) = std::env::var("COLORTERM") {
+            let colorterm = colorterm.to_lowercase();
+            if colorterm.contains("truecolor") || colorterm.contains("24bit") {
+                return ColorDepth::TrueColor;
+            }
+        }
+        match std::env::var("TERM") {
+            Ok(term) if term.ends_with("-256color") => ColorDepth::Ansi256,
+            _ => ColorDepth::Ansi16,
+        }
+    }
+}
+
+/// Renders a piece of text in a scheme color. Implementations decide whether
+/// to emit escape sequences, letting the same formatting recursion serve both
+/// colored and plain output.
+trait ColorSink {
+    fn paint(&self, text: &str, color: &CsColor) -> String;
+}
+
+/// Emits color escapes at a chosen depth, downgrading scheme colors to the
+/// nearest ANSI palette entry when the terminal can't render truecolor.
+struct ColorFormatter {
+    depth: ColorDepth,
+}
+
+impl ColorSink for ColorFormatter {
+    fn paint(&self, text: &str, color: &CsColor) -> String {
+        match self.depth {
+            ColorDepth::TrueColor => text.truecolor(color.r, color.g, color.b).to_string(),
+            ColorDepth::Ansi256 => {
+                format!("\x1b[38;5;{}m{}\x1b[0m", nearest_ansi256(color), text)
+            }
+            ColorDepth::Ansi16 => format!("\x1b[{}m{}\x1b[0m", nearest_ansi16(color), text),
+        }
+    }
+}
+
+/// Squared Euclidean distance between two RGB triples.
+fn rgb_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> i32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Map a color to the nearest xterm 256-color index, considering both the
+/// 6×6×6 color cube and the 24-step grayscale ramp for near-gray colors.
+fn nearest_ansi256(color: &CsColor) -> u8 {
+    let rgb = (color.r, color.g, color.b);
+    let quantize = |v: u8| (v as f64 / 255.0 * 5.0).round() as u8;
+    // xterm cube levels are 0, then 55 + 40*n.
+    let cube_level = |n: u8| if n == 0 { 0 } else { 55 + 40 * n };
+
+    let (cr, cg, cb) = (quantize(rgb.0), quantize(rgb.1), quantize(rgb.2));
+    let cube_index = 16 + 36 * cr + 6 * cg + cb;
+    let cube_rgb = (cube_level(cr), cube_level(cg), cube_level(cb));
+
+    let mut best_index = cube_index;
+    let mut best_distance = rgb_distance(rgb, cube_rgb);
+
+    // Only weigh the grayscale ramp when the channels are close to equal.
+    let max = rgb.0.max(rgb.1).max(rgb.2);
+    let min = rgb.0.min(rgb.1).min(rgb.2);
+    if max - min <= 16 {
+        let gray = ((rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3) as u8;
+        let step = (((gray as f64) - 8.0) / 10.0).round().clamp(0.0, 23.0) as u8;
+        let gray_value = 8 + 10 * step;
+        let gray_distance = rgb_distance(rgb, (gray_value, gray_value, gray_value));
+        if gray_distance < best_distance {
+            best_index = 232 + step;
+        }
+    }
+
+    best_index
+}
+
+/// Map a color to the SGR code of the nearest of the 16 standard ANSI colors.
+fn nearest_ansi16(color: &CsColor) -> u8 {
+    const PALETTE: &[((u8, u8, u8), u8)] = &[
+        ((0, 0, 0), 30),
+        ((170, 0, 0), 31),
+        ((0, 170, 0), 32),
+        ((170, 85, 0), 33),
+        ((0, 0, 170), 34),
+        ((170, 0, 170), 35),
+        ((0, 170, 170), 36),
+        ((170, 170, 170), 37),
+        ((85, 85, 85), 90),
+        ((255, 85, 85), 91),
+        ((85, 255, 85), 92),
+        ((255, 255, 85), 93),
+        ((85, 85, 255), 94),
+        ((255, 85, 255), 95),
+        ((85, 255, 255), 96),
+        ((255, 255, 255), 97),
+    ];
+
+    let rgb = (color.r, color.g, color.b);
+    PALETTE
+        .iter()
+        .min_by_key(|(candidate, _)| rgb_distance(rgb, *candidate))
+        .map(|(_, sgr)| *sgr)
+        .unwrap_or(37)
+}
+
+/// Emits the text unchanged, with no escape sequences.
+struct PlainFormatter;
+
+impl ColorSink for PlainFormatter {
+    fn paint(&self, text: &str, _color: &CsColor) -> String {
+        text.to_string()
+    }
 }
 
 fn main() {
@@ -20,6 +199,11 @@ fn main() {
     if let Some(color_scheme) = colors_schemes.get(&opt.color_scheme) {
         let format_service = FormatService {
             colors: color_scheme,
+            formatter: opt
+                .color
+                .formatter(opt.color_depth.unwrap_or_else(ColorDepth::detect)),
+            render_embedded: opt.render_embedded_color,
+            highlight_level: opt.highlight_level,
         };
         let mut buffer = String::new();
 
@@ -43,11 +227,140 @@ fn main() {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 struct CsColor {
     r: u8,
     g: u8,
     b: u8,
+    /// Alpha channel. Parsed from the `#RRGGBBAA` form and retained for future
+    /// use; the struct and 6-digit hex forms default it to fully opaque.
+    a: u8,
+}
+
+/// Expected-value description shared by the error path and the visitor.
+const CS_COLOR_EXPECTED: &str = "#RRGGBB[AA], a color name, or {r,g,b}";
+
+impl<'de> Deserialize<'de> for CsColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// Struct form of a color, deserialized through the map branch.
+        #[derive(Deserialize)]
+        struct Rgb {
+            r: u8,
+            g: u8,
+            b: u8,
+        }
+
+        struct CsColorVisitor;
+
+        impl<'de> Visitor<'de> for CsColorVisitor {
+            type Value = CsColor;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(CS_COLOR_EXPECTED)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                parse_color_str(value)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &CS_COLOR_EXPECTED))
+            }
+
+            fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let Rgb { r, g, b } =
+                    Rgb::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+                Ok(CsColor { r, g, b, a: 0xff })
+            }
+        }
+
+        deserializer.deserialize_any(CsColorVisitor)
+    }
+}
+
+/// Parse the string form of a color: `#RRGGBB`, `#RRGGBBAA`, or an X11 color
+/// name. Returns `None` when the value matches none of these.
+fn parse_color_str(value: &str) -> Option<CsColor> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    if let 6 | 8 = hex.len() {
+        if let Ok(packed) = u32::from_str_radix(hex, 16) {
+            return Some(if hex.len() == 6 {
+                CsColor {
+                    r: (packed >> 16) as u8,
+                    g: (packed >> 8) as u8,
+                    b: packed as u8,
+                    a: 0xff,
+                }
+            } else {
+                CsColor {
+                    r: (packed >> 24) as u8,
+                    g: (packed >> 16) as u8,
+                    b: (packed >> 8) as u8,
+                    a: packed as u8,
+                }
+            });
+        }
+    }
+
+    x11_color(&value.to_lowercase()).map(|(r, g, b)| CsColor { r, g, b, a: 0xff })
+}
+
+/// The X11 color-name table, resolving a lowercased name to its RGB triple.
+fn x11_color(name: &str) -> Option<(u8, u8, u8)> {
+    const NAMES: &[(&str, (u8, u8, u8))] = &[
+        ("black", (0, 0, 0)),
+        ("white", (255, 255, 255)),
+        ("red", (255, 0, 0)),
+        ("green", (0, 128, 0)),
+        ("lime", (0, 255, 0)),
+        ("blue", (0, 0, 255)),
+        ("yellow", (255, 255, 0)),
+        ("cyan", (0, 255, 255)),
+        ("magenta", (255, 0, 255)),
+        ("gray", (128, 128, 128)),
+        ("grey", (128, 128, 128)),
+        ("silver", (192, 192, 192)),
+        ("maroon", (128, 0, 0)),
+        ("olive", (128, 128, 0)),
+        ("navy", (0, 0, 128)),
+        ("purple", (128, 0, 128)),
+        ("teal", (0, 128, 128)),
+        ("orange", (255, 165, 0)),
+        ("gold", (255, 215, 0)),
+        ("pink", (255, 192, 203)),
+        ("brown", (165, 42, 42)),
+        ("coral", (255, 127, 80)),
+        ("salmon", (250, 128, 114)),
+        ("tomato", (255, 99, 71)),
+        ("steelblue", (70, 130, 180)),
+        ("skyblue", (135, 206, 235)),
+        ("royalblue", (65, 105, 225)),
+        ("dodgerblue", (30, 144, 255)),
+        ("midnightblue", (25, 25, 112)),
+        ("turquoise", (64, 224, 208)),
+        ("forestgreen", (34, 139, 34)),
+        ("seagreen", (46, 139, 87)),
+        ("limegreen", (50, 205, 50)),
+        ("darkgreen", (0, 100, 0)),
+        ("khaki", (240, 230, 140)),
+        ("chocolate", (210, 105, 30)),
+        ("crimson", (220, 20, 60)),
+        ("violet", (238, 130, 238)),
+        ("indigo", (75, 0, 130)),
+        ("slategray", (112, 128, 144)),
+        ("slategrey", (112, 128, 144)),
+    ];
+
+    NAMES
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, rgb)| *rgb)
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,10 +370,122 @@ struct ColorScheme {
     number: CsColor,
     string: CsColor,
     object_key: CsColor,
+    /// Per-severity colors keyed by lowercased level name (`debug`, `info`,
+    /// `warn`, `error`, `fatal`, ...). Empty when the scheme opts out.
+    #[serde(default)]
+    levels: HashMap<String, CsColor>,
+    /// Color for timestamp fields, when the scheme defines one.
+    #[serde(default)]
+    timestamp: Option<CsColor>,
+    /// Color for message fields, when the scheme defines one.
+    #[serde(default)]
+    message: Option<CsColor>,
+}
+
+/// Keys whose string value names a log severity.
+fn is_level_key(key: &str) -> bool {
+    matches!(key, "level" | "severity")
+}
+
+/// Keys that carry a log timestamp.
+fn is_timestamp_key(key: &str) -> bool {
+    matches!(key, "timestamp" | "ts")
+}
+
+/// Keys that carry the log message.
+fn is_message_key(key: &str) -> bool {
+    matches!(key, "msg" | "message")
 }
 
 struct FormatService<'a> {
     colors: &'a ColorScheme,
+    formatter: Box<dyn ColorSink>,
+    render_embedded: bool,
+    highlight_level: bool,
+}
+
+/// A run of a string value produced by [`scan_embedded`].
+enum Segment {
+    /// Visible text; `color` is a caret-code override, or `None` to use the
+    /// scheme's string color.
+    Text {
+        text: String,
+        color: Option<(u8, u8, u8)>,
+    },
+    /// A raw ANSI escape already present in the string, passed through verbatim.
+    Raw(String),
+}
+
+/// Resolve a caret color code (`^0`..`^7`) to its RGB triple, following the
+/// Quake/Minecraft ordering (black, red, green, yellow, blue, cyan, magenta,
+/// white).
+fn caret_color(digit: char) -> Option<(u8, u8, u8)> {
+    match digit {
+        '0' => Some((0, 0, 0)),
+        '1' => Some((170, 0, 0)),
+        '2' => Some((0, 170, 0)),
+        '3' => Some((170, 85, 0)),
+        '4' => Some((0, 0, 170)),
+        '5' => Some((0, 170, 170)),
+        '6' => Some((170, 0, 170)),
+        '7' => Some((170, 170, 170)),
+        _ => None,
+    }
+}
+
+/// Split a string value into colored segments, recognizing caret codes and
+/// leaving any embedded ANSI SGR escapes untouched.
+fn scan_embedded(s: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut active: Option<(u8, u8, u8)> = None;
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '^' => match chars.peek().and_then(|d| caret_color(*d)) {
+                Some(color) => {
+                    if !current.is_empty() {
+                        segments.push(Segment::Text {
+                            text: std::mem::take(&mut current),
+                            color: active,
+                        });
+                    }
+                    active = Some(color);
+                    chars.next();
+                }
+                None => current.push('^'),
+            },
+            '\x1b' => {
+                let mut escape = String::from('\x1b');
+                // A CSI SGR sequence runs up to and including the final `m`.
+                while let Some(&next) = chars.peek() {
+                    escape.push(next);
+                    chars.next();
+                    if next == 'm' {
+                        break;
+                    }
+                }
+                if !current.is_empty() {
+                    segments.push(Segment::Text {
+                        text: std::mem::take(&mut current),
+                        color: active,
+                    });
+                }
+                segments.push(Segment::Raw(escape));
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        segments.push(Segment::Text {
+            text: current,
+            color: active,
+        });
+    }
+
+    segments
 }
 
 impl<'a> FormatService<'a> {
@@ -74,33 +499,38 @@ impl<'a> FormatService<'a> {
 
     fn format_json(&self, value: &Value, depth: usize) -> String {
         match value {
-            Value::Null => "null"
-                .truecolor(self.colors.null.r, self.colors.null.g, self.colors.null.b)
-                .to_string(),
-            Value::Bool(b) => b
-                .to_string()
-                .truecolor(self.colors.bool.r, self.colors.bool.g, self.colors.bool.b)
-                .to_string(),
-            Value::Number(n) => n
-                .to_string()
-                .truecolor(
-                    self.colors.number.r,
-                    self.colors.number.g,
-                    self.colors.number.b,
-                )
-                .to_string(),
-            Value::String(s) => format!("\"{}\"", s)
-                .truecolor(
-                    self.colors.string.r,
-                    self.colors.string.g,
-                    self.colors.string.b,
-                )
-                .to_string(),
+            Value::Null => self.formatter.paint("null", &self.colors.null),
+            Value::Bool(b) => self.formatter.paint(&b.to_string(), &self.colors.bool),
+            Value::Number(n) => self.formatter.paint(&n.to_string(), &self.colors.number),
+            Value::String(s) => self.format_string(s),
             Value::Array(a) => self.format_array(a, depth + 1),
             Value::Object(o) => self.format_object(o, depth + 1),
         }
     }
 
+    fn format_string(&self, s: &str) -> String {
+        if !self.render_embedded {
+            return self
+                .formatter
+                .paint(&format!("\"{}\"", s), &self.colors.string);
+        }
+
+        let quote = self.formatter.paint("\"", &self.colors.string);
+        let mut out = quote.clone();
+        for segment in scan_embedded(s) {
+            match segment {
+                Segment::Text { text, color } => {
+                    let overridden = color.map(|(r, g, b)| CsColor { r, g, b, a: 0xff });
+                    let color = overridden.as_ref().unwrap_or(&self.colors.string);
+                    out.push_str(&self.formatter.paint(&text, color));
+                }
+                Segment::Raw(raw) => out.push_str(&raw),
+            }
+        }
+        out.push_str(&quote);
+        out
+    }
+
     fn format_array(&self, values: &[Value], depth: usize) -> String {
         let contents: Vec<String> = values
             .iter()
@@ -111,24 +541,86 @@ impl<'a> FormatService<'a> {
     }
 
     fn format_object(&self, map: &Map<String, Value>, depth: usize) -> String {
+        let line_tint = if self.highlight_level {
+            self.line_level_color(map)
+        } else {
+            None
+        };
+
         let contents: Vec<String> = map
             .iter()
             .map(|(k, v)| {
+                let key_color = line_tint.unwrap_or(&self.colors.object_key);
                 format!(
                     "{}{}: {}",
                     indent(depth),
-                    k.truecolor(
-                        self.colors.object_key.r,
-                        self.colors.object_key.g,
-                        self.colors.object_key.b
-                    ),
-                    self.format_json(v, depth)
+                    self.formatter.paint(k, key_color),
+                    self.format_value_for_key(k, v, depth, line_tint)
                 )
             })
             .collect();
 
         format!("{{\n{}\n{}}}", contents.join(",\n"), indent(depth - 1))
     }
+
+    /// Color a value according to its key's semantic role, falling back to the
+    /// flat per-type coloring. When `tint` is set (whole-line highlighting),
+    /// every scalar takes the level color.
+    fn format_value_for_key(
+        &self,
+        key: &str,
+        value: &Value,
+        depth: usize,
+        tint: Option<&CsColor>,
+    ) -> String {
+        if let Some(tint) = tint {
+            return self.paint_scalar(value, tint, depth);
+        }
+
+        let key = key.to_lowercase();
+        if is_level_key(&key) {
+            if let Value::String(level) = value {
+                if let Some(color) = self.colors.levels.get(&level.to_lowercase()) {
+                    return self.formatter.paint(&format!("\"{}\"", level), color);
+                }
+            }
+        } else if is_timestamp_key(&key) {
+            if let Some(color) = &self.colors.timestamp {
+                return self.paint_scalar(value, color, depth);
+            }
+        } else if is_message_key(&key) {
+            if let Some(color) = &self.colors.message {
+                return self.paint_scalar(value, color, depth);
+            }
+        }
+
+        self.format_json(value, depth)
+    }
+
+    /// Paint a scalar value with `color`, recursing into containers unchanged.
+    fn paint_scalar(&self, value: &Value, color: &CsColor, depth: usize) -> String {
+        match value {
+            Value::Null => self.formatter.paint("null", color),
+            Value::Bool(b) => self.formatter.paint(&b.to_string(), color),
+            Value::Number(n) => self.formatter.paint(&n.to_string(), color),
+            Value::String(s) => self.formatter.paint(&format!("\"{}\"", s), color),
+            Value::Array(a) => self.format_array(a, depth + 1),
+            Value::Object(o) => self.format_object(o, depth + 1),
+        }
+    }
+
+    /// Find the level color for an object, if it carries a level-like key whose
+    /// string value names a severity defined by the scheme.
+    fn line_level_color(&self, map: &Map<String, Value>) -> Option<&CsColor> {
+        map.iter().find_map(|(k, v)| {
+            if is_level_key(&k.to_lowercase()) {
+                if let Value::String(level) = v {
+                    return self.colors.levels.get(&level.to_lowercase());
+                }
+            }
+            None
+        })
+    }
 }
 
 fn indent(depth: usize) -> String {